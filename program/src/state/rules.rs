@@ -6,12 +6,25 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
 };
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 use super::{FrequencyAccount, SolanaAccount};
 
+/// Comparison used by [`Rule::Amount`] when checking `payload.amount`
+/// against the rule's configured `amount`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum AmountOperator {
+    Eq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 pub enum Rule {
     All {
@@ -31,6 +44,7 @@ pub enum Rule {
         field: PayloadKey,
     },
     DerivedKeyMatch {
+        program: Pubkey,
         account: Pubkey,
         field: PayloadKey,
     },
@@ -40,6 +54,7 @@ pub enum Rule {
     },
     Amount {
         amount: u64,
+        operator: AmountOperator,
     },
     Frequency {
         freq_name: String,
@@ -49,6 +64,15 @@ pub enum Rule {
         root: [u8; 32],
         field: PayloadKey,
     },
+    IsRentExempt {
+        field: PayloadKey,
+    },
+    ProgramOwnedDataMatch {
+        program: Pubkey,
+        field: PayloadKey,
+        data_offset: u64,
+        expected: Vec<u8>,
+    },
 }
 
 impl Rule {
@@ -134,7 +158,11 @@ impl Rule {
                     (false, self.to_error())
                 }
             }
-            Rule::DerivedKeyMatch { account, field } => {
+            Rule::DerivedKeyMatch {
+                program,
+                account,
+                field,
+            } => {
                 msg!("Validating DerivedKeyMatch");
 
                 let seeds = match payload.get_seeds(*field) {
@@ -148,7 +176,7 @@ impl Rule {
                     .map(Vec::as_slice)
                     .collect::<Vec<&[u8]>>();
                 let seeds = &vec_of_slices[..];
-                if let Ok(_bump) = assert_derivation(&crate::id(), account, seeds) {
+                if let Ok(_bump) = assert_derivation(program, account, seeds) {
                     (true, self.to_error())
                 } else {
                     (false, self.to_error())
@@ -170,14 +198,22 @@ impl Rule {
 
                 (false, self.to_error())
             }
-            Rule::Amount { amount } => {
+            Rule::Amount { amount, operator } => {
                 msg!("Validating Amount");
-                if let Some(payload_amount) = &payload.amount {
-                    if amount == payload_amount {
-                        (true, self.to_error())
-                    } else {
-                        (false, self.to_error())
-                    }
+                if let Some(payload_amount) = payload.amount {
+                    // Compared directly on the raw `u64`s with no intermediate
+                    // casts, since `payload_amount` or `amount` may legitimately
+                    // be `u64::MAX`. Plain `u64` comparisons can't overflow, so
+                    // unlike `Frequency`'s `checked_add`, there's no arithmetic
+                    // edge here that would produce `RuleSetError::NumericalOverflow`.
+                    let result = match operator {
+                        AmountOperator::Eq => payload_amount == *amount,
+                        AmountOperator::Lt => payload_amount < *amount,
+                        AmountOperator::LtEq => payload_amount <= *amount,
+                        AmountOperator::Gt => payload_amount > *amount,
+                        AmountOperator::GtEq => payload_amount >= *amount,
+                    };
+                    (result, self.to_error())
                 } else {
                     (false, self.to_error())
                 }
@@ -250,6 +286,78 @@ impl Rule {
                     (false, self.to_error())
                 }
             }
+            Rule::IsRentExempt { field } => {
+                msg!("Validating IsRentExempt");
+
+                let key = match payload.get_pubkey(*field) {
+                    Some(pubkey) => pubkey,
+                    _ => return (false, self.to_error()),
+                };
+
+                let account = match accounts.get(&key) {
+                    Some(account) => account,
+                    _ => return (false, self.to_error()),
+                };
+
+                let rent = match Rent::get() {
+                    Ok(rent) => rent,
+                    Err(_) => return (false, self.to_error()),
+                };
+
+                let minimum_balance = rent.minimum_balance(account.data_len());
+                if account.lamports() >= minimum_balance {
+                    (true, self.to_error())
+                } else {
+                    (false, self.to_error())
+                }
+            }
+            Rule::ProgramOwnedDataMatch {
+                program,
+                field,
+                data_offset,
+                expected,
+            } => {
+                msg!("Validating ProgramOwnedDataMatch");
+
+                let key = match payload.get_pubkey(*field) {
+                    Some(pubkey) => pubkey,
+                    _ => return (false, self.to_error()),
+                };
+
+                let account = match accounts.get(&key) {
+                    Some(account) => account,
+                    _ => return (false, self.to_error()),
+                };
+
+                if *account.owner != *program {
+                    return (false, self.to_error());
+                }
+
+                let data_offset = match usize::try_from(*data_offset) {
+                    Ok(data_offset) => data_offset,
+                    Err(_) => return (false, RuleSetError::NumericalOverflow),
+                };
+
+                let end = match data_offset.checked_add(expected.len()) {
+                    Some(end) => end,
+                    None => return (false, RuleSetError::NumericalOverflow),
+                };
+
+                if end > account.data_len() {
+                    return (false, self.to_error());
+                }
+
+                let data = match account.try_borrow_data() {
+                    Ok(data) => data,
+                    Err(_) => return (false, self.to_error()),
+                };
+
+                if data[data_offset..end] == expected[..] {
+                    (true, self.to_error())
+                } else {
+                    (false, self.to_error())
+                }
+            }
         }
     }
 
@@ -307,7 +415,541 @@ impl Rule {
             Rule::Amount { .. } => RuleSetError::AmountCheckFailed,
             Rule::Frequency { .. } => RuleSetError::FrequencyCheckFailed,
             Rule::PubkeyTreeMatch { .. } => RuleSetError::PubkeyTreeMatchCheckFailed,
+            Rule::IsRentExempt { .. } => RuleSetError::RentExemptCheckFailed,
+            Rule::ProgramOwnedDataMatch { .. } => RuleSetError::ProgramOwnedDataMatchCheckFailed,
             _ => RuleSetError::NotImplemented,
         }
     }
+
+    // Serializes `self` tagged with the current version, for storage in
+    // account data. Use this instead of calling `bincode::serialize` on a
+    // `Rule` directly.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, RuleSetError> {
+        let mut bytes = vec![RULE_VERSION_TAG, RuleSetVersion::CURRENT as u8];
+        bytes.extend(bincode::serialize(self).map_err(|_| RuleSetError::DataTypeMismatch)?);
+        Ok(bytes)
+    }
+
+    // Deserializes a `Rule` tree written by `to_bytes`, migrating older
+    // layouts up to the current shape. Falls back to decoding `data` as an
+    // untagged legacy layout if it has no version tag.
+    pub fn from_bytes(data: &[u8]) -> Result<Rule, RuleSetError> {
+        // The version tag unambiguously identifies the decoder to use, so
+        // trailing bytes (e.g. zero padding in an over-allocated account
+        // buffer) after a validly-tagged rule tree are harmless here.
+        if let [RULE_VERSION_TAG, version, rest @ ..] = data {
+            return match RuleSetVersion::try_from(*version)? {
+                RuleSetVersion::V1 => {
+                    let legacy: LegacyRuleV1 =
+                        bincode::deserialize(rest).map_err(|_| RuleSetError::DataTypeMismatch)?;
+                    Ok(Rule::from(legacy))
+                }
+                RuleSetVersion::V2 => {
+                    let legacy: LegacyRuleV2 =
+                        bincode::deserialize(rest).map_err(|_| RuleSetError::DataTypeMismatch)?;
+                    Ok(Rule::from(legacy))
+                }
+                RuleSetVersion::V3 => {
+                    bincode::deserialize(rest).map_err(|_| RuleSetError::DataTypeMismatch)
+                }
+            };
+        }
+
+        // No tag to disambiguate with, so `data` must be an untagged rule
+        // tree written by the original, pre-versioning path. Prefer a
+        // decode that consumes `data` exactly: `bincode::deserialize`
+        // otherwise happily ignores trailing bytes, so decoding with the
+        // wrong (newer) legacy layout could spuriously "succeed" by reading
+        // a following sibling rule's bytes as if they were this rule's
+        // missing fields. Only once neither layout decodes exactly do we
+        // fall back to a lenient decode, the same way the tagged branch
+        // above tolerates trailing bytes (e.g. zero padding in an
+        // over-allocated account buffer).
+        if let Some(legacy) = decode_exact::<LegacyRuleV2>(data) {
+            return Ok(Rule::from(legacy));
+        }
+        if let Some(legacy) = decode_exact::<LegacyRuleV1>(data) {
+            return Ok(Rule::from(legacy));
+        }
+        if let Ok(legacy) = bincode::deserialize::<LegacyRuleV2>(data) {
+            return Ok(Rule::from(legacy));
+        }
+        if let Ok(legacy) = bincode::deserialize::<LegacyRuleV1>(data) {
+            return Ok(Rule::from(legacy));
+        }
+
+        Err(RuleSetError::DataTypeMismatch)
+    }
+
+    // Upgrades a stored rule tree to the current version, in place.
+    pub fn migrate_to_current(data: &mut Vec<u8>) -> Result<(), RuleSetError> {
+        let rule = Rule::from_bytes(data)?;
+        *data = rule.to_bytes()?;
+        Ok(())
+    }
+}
+
+// Leading byte `Rule::to_bytes` prefixes a rule tree with, ahead of the
+// version byte. Bincode encodes an enum variant index as a 4-byte
+// little-endian `u32`, so a legacy, untagged `Rule`'s first byte is always
+// a small variant index, well under this value — that's what lets
+// `Rule::from_bytes` tell tagged data from untagged legacy data on sight.
+const RULE_VERSION_TAG: u8 = 0xFF;
+
+// Deserializes `data` as `T` only if doing so consumes `data` exactly;
+// `bincode::deserialize` otherwise silently ignores trailing bytes.
+fn decode_exact<T: serde::de::DeserializeOwned + Serialize>(data: &[u8]) -> Option<T> {
+    let value: T = bincode::deserialize(data).ok()?;
+    if bincode::serialized_size(&value).ok()? == data.len() as u64 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+// On-chain serialization format version for a stored `Rule` tree, so
+// that rule sets written by older program versions can still be read,
+// and a rule set written by an unknown future version is rejected
+// outright instead of being silently misparsed.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[repr(u8)]
+pub enum RuleSetVersion {
+    // Layout before `Amount` gained `operator`, and before `IsRentExempt` /
+    // `ProgramOwnedDataMatch` existed.
+    V1 = 1,
+    // Layout before `DerivedKeyMatch` gained `program`.
+    V2 = 2,
+    V3 = 3,
+}
+
+impl RuleSetVersion {
+    pub const CURRENT: RuleSetVersion = RuleSetVersion::V3;
+}
+
+impl TryFrom<u8> for RuleSetVersion {
+    type Error = RuleSetError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(RuleSetVersion::V1),
+            2 => Ok(RuleSetVersion::V2),
+            3 => Ok(RuleSetVersion::V3),
+            _ => Err(RuleSetError::UnknownRuleSetVersion),
+        }
+    }
+}
+
+// The `Rule` layout as it existed in `RuleSetVersion::V1` rule sets.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+enum LegacyRuleV1 {
+    All {
+        rules: Vec<LegacyRuleV1>,
+    },
+    Any {
+        rules: Vec<LegacyRuleV1>,
+    },
+    Not {
+        rule: Box<LegacyRuleV1>,
+    },
+    AdditionalSigner {
+        account: Pubkey,
+    },
+    PubkeyMatch {
+        pubkey: Pubkey,
+        field: PayloadKey,
+    },
+    DerivedKeyMatch {
+        account: Pubkey,
+        field: PayloadKey,
+    },
+    ProgramOwned {
+        program: Pubkey,
+        field: PayloadKey,
+    },
+    Amount {
+        amount: u64,
+    },
+    Frequency {
+        freq_name: String,
+        freq_account: Pubkey,
+    },
+    PubkeyTreeMatch {
+        root: [u8; 32],
+        field: PayloadKey,
+    },
+}
+
+// Upgrades a `RuleSetVersion::V1` rule tree to the `RuleSetVersion::V2` shape.
+impl From<LegacyRuleV1> for LegacyRuleV2 {
+    fn from(rule: LegacyRuleV1) -> Self {
+        match rule {
+            LegacyRuleV1::All { rules } => LegacyRuleV2::All {
+                rules: rules.into_iter().map(LegacyRuleV2::from).collect(),
+            },
+            LegacyRuleV1::Any { rules } => LegacyRuleV2::Any {
+                rules: rules.into_iter().map(LegacyRuleV2::from).collect(),
+            },
+            LegacyRuleV1::Not { rule } => LegacyRuleV2::Not {
+                rule: Box::new(LegacyRuleV2::from(*rule)),
+            },
+            LegacyRuleV1::AdditionalSigner { account } => {
+                LegacyRuleV2::AdditionalSigner { account }
+            }
+            LegacyRuleV1::PubkeyMatch { pubkey, field } => {
+                LegacyRuleV2::PubkeyMatch { pubkey, field }
+            }
+            LegacyRuleV1::DerivedKeyMatch { account, field } => {
+                LegacyRuleV2::DerivedKeyMatch { account, field }
+            }
+            LegacyRuleV1::ProgramOwned { program, field } => {
+                LegacyRuleV2::ProgramOwned { program, field }
+            }
+            LegacyRuleV1::Amount { amount } => LegacyRuleV2::Amount {
+                amount,
+                operator: AmountOperator::Eq,
+            },
+            LegacyRuleV1::Frequency {
+                freq_name,
+                freq_account,
+            } => LegacyRuleV2::Frequency {
+                freq_name,
+                freq_account,
+            },
+            LegacyRuleV1::PubkeyTreeMatch { root, field } => {
+                LegacyRuleV2::PubkeyTreeMatch { root, field }
+            }
+        }
+    }
+}
+
+impl From<LegacyRuleV1> for Rule {
+    fn from(rule: LegacyRuleV1) -> Self {
+        Rule::from(LegacyRuleV2::from(rule))
+    }
+}
+
+// The `Rule` layout as it existed in `RuleSetVersion::V2` rule sets.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+enum LegacyRuleV2 {
+    All {
+        rules: Vec<LegacyRuleV2>,
+    },
+    Any {
+        rules: Vec<LegacyRuleV2>,
+    },
+    Not {
+        rule: Box<LegacyRuleV2>,
+    },
+    AdditionalSigner {
+        account: Pubkey,
+    },
+    PubkeyMatch {
+        pubkey: Pubkey,
+        field: PayloadKey,
+    },
+    DerivedKeyMatch {
+        account: Pubkey,
+        field: PayloadKey,
+    },
+    ProgramOwned {
+        program: Pubkey,
+        field: PayloadKey,
+    },
+    Amount {
+        amount: u64,
+        operator: AmountOperator,
+    },
+    Frequency {
+        freq_name: String,
+        freq_account: Pubkey,
+    },
+    PubkeyTreeMatch {
+        root: [u8; 32],
+        field: PayloadKey,
+    },
+    IsRentExempt {
+        field: PayloadKey,
+    },
+    ProgramOwnedDataMatch {
+        program: Pubkey,
+        field: PayloadKey,
+        data_offset: u64,
+        expected: Vec<u8>,
+    },
+}
+
+impl From<LegacyRuleV2> for Rule {
+    fn from(rule: LegacyRuleV2) -> Self {
+        match rule {
+            LegacyRuleV2::All { rules } => Rule::All {
+                rules: rules.into_iter().map(Rule::from).collect(),
+            },
+            LegacyRuleV2::Any { rules } => Rule::Any {
+                rules: rules.into_iter().map(Rule::from).collect(),
+            },
+            LegacyRuleV2::Not { rule } => Rule::Not {
+                rule: Box::new(Rule::from(*rule)),
+            },
+            LegacyRuleV2::AdditionalSigner { account } => Rule::AdditionalSigner { account },
+            LegacyRuleV2::PubkeyMatch { pubkey, field } => Rule::PubkeyMatch { pubkey, field },
+            LegacyRuleV2::DerivedKeyMatch { account, field } => Rule::DerivedKeyMatch {
+                program: crate::id(),
+                account,
+                field,
+            },
+            LegacyRuleV2::ProgramOwned { program, field } => {
+                Rule::ProgramOwned { program, field }
+            }
+            LegacyRuleV2::Amount { amount, operator } => Rule::Amount { amount, operator },
+            LegacyRuleV2::Frequency {
+                freq_name,
+                freq_account,
+            } => Rule::Frequency {
+                freq_name,
+                freq_account,
+            },
+            LegacyRuleV2::PubkeyTreeMatch { root, field } => {
+                Rule::PubkeyTreeMatch { root, field }
+            }
+            LegacyRuleV2::IsRentExempt { field } => Rule::IsRentExempt { field },
+            LegacyRuleV2::ProgramOwnedDataMatch {
+                program,
+                field,
+                data_offset,
+                expected,
+            } => Rule::ProgramOwnedDataMatch {
+                program,
+                field,
+                data_offset,
+                expected,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+    struct TestSyscallStubs;
+
+    impl SyscallStubs for TestSyscallStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe {
+                *(var_addr as *mut Rent) = Rent::default();
+            }
+            0
+        }
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_round_trip() {
+        let rule = Rule::Amount {
+            amount: 7,
+            operator: AmountOperator::GtEq,
+        };
+        let bytes = rule.to_bytes().unwrap();
+        assert_eq!(Rule::from_bytes(&bytes).unwrap(), rule);
+    }
+
+    #[test]
+    fn from_bytes_decodes_untagged_legacy_v1() {
+        let legacy = LegacyRuleV1::Amount { amount: 42 };
+        let bytes = bincode::serialize(&legacy).unwrap();
+        let decoded = Rule::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            decoded,
+            Rule::Amount {
+                amount: 42,
+                operator: AmountOperator::Eq,
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_decodes_untagged_legacy_v2() {
+        let legacy = LegacyRuleV2::ProgramOwnedDataMatch {
+            program: Pubkey::new_from_array([7; 32]),
+            field: PayloadKey::Target,
+            data_offset: 4,
+            expected: vec![1, 2, 3],
+        };
+        let bytes = bincode::serialize(&legacy).unwrap();
+        let decoded = Rule::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            decoded,
+            Rule::ProgramOwnedDataMatch {
+                program: Pubkey::new_from_array([7; 32]),
+                field: PayloadKey::Target,
+                data_offset: 4,
+                expected: vec![1, 2, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_rejects_unknown_version() {
+        let bytes = vec![RULE_VERSION_TAG, 99];
+        assert!(matches!(
+            Rule::from_bytes(&bytes),
+            Err(RuleSetError::UnknownRuleSetVersion)
+        ));
+    }
+
+    #[test]
+    fn from_bytes_tolerates_trailing_padding_in_tagged_data() {
+        let rule = Rule::AdditionalSigner {
+            account: Pubkey::new_from_array([9; 32]),
+        };
+        let mut bytes = rule.to_bytes().unwrap();
+        bytes.extend([0u8; 16]);
+        assert_eq!(Rule::from_bytes(&bytes).unwrap(), rule);
+    }
+
+    #[test]
+    fn from_bytes_tolerates_trailing_padding_in_untagged_legacy_data() {
+        let legacy = LegacyRuleV1::AdditionalSigner {
+            account: Pubkey::new_from_array([3; 32]),
+        };
+        let mut bytes = bincode::serialize(&legacy).unwrap();
+        bytes.extend([0u8; 16]);
+        let decoded = Rule::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            decoded,
+            Rule::AdditionalSigner {
+                account: Pubkey::new_from_array([3; 32]),
+            }
+        );
+    }
+
+    #[test]
+    fn program_owned_data_match_exact_end_boundary_passes() {
+        let key = Pubkey::new_from_array([1; 32]);
+        let program = Pubkey::new_from_array([2; 32]);
+        let mut lamports = 0u64;
+        let mut data = vec![0xAA, 0xBB, 0xCC];
+        let account_info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &program, false, 0);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(key, &account_info);
+        let payload = ParsedPayload {
+            target: Some(key),
+            ..Default::default()
+        };
+        let rule = Rule::ProgramOwnedDataMatch {
+            program,
+            field: PayloadKey::Target,
+            data_offset: 1,
+            expected: vec![0xBB, 0xCC],
+        };
+
+        let (result, _) = rule.ll_validate(&accounts, &payload);
+        assert!(result);
+    }
+
+    #[test]
+    fn program_owned_data_match_zero_length_expected_at_data_len_passes() {
+        let key = Pubkey::new_from_array([1; 32]);
+        let program = Pubkey::new_from_array([2; 32]);
+        let mut lamports = 0u64;
+        let mut data = vec![0xAA, 0xBB, 0xCC];
+        let account_info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &program, false, 0);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(key, &account_info);
+        let payload = ParsedPayload {
+            target: Some(key),
+            ..Default::default()
+        };
+        let rule = Rule::ProgramOwnedDataMatch {
+            program,
+            field: PayloadKey::Target,
+            data_offset: 3,
+            expected: vec![],
+        };
+
+        let (result, _) = rule.ll_validate(&accounts, &payload);
+        assert!(result);
+    }
+
+    #[test]
+    fn program_owned_data_match_offset_past_data_len_fails() {
+        let key = Pubkey::new_from_array([1; 32]);
+        let program = Pubkey::new_from_array([2; 32]);
+        let mut lamports = 0u64;
+        let mut data = vec![0xAA, 0xBB, 0xCC];
+        let account_info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &program, false, 0);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(key, &account_info);
+        let payload = ParsedPayload {
+            target: Some(key),
+            ..Default::default()
+        };
+        let rule = Rule::ProgramOwnedDataMatch {
+            program,
+            field: PayloadKey::Target,
+            data_offset: 4,
+            expected: vec![],
+        };
+
+        let (result, _) = rule.ll_validate(&accounts, &payload);
+        assert!(!result);
+    }
+
+    #[test]
+    fn is_rent_exempt_passes_at_exact_minimum_balance() {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+
+        let key = Pubkey::new_from_array([4; 32]);
+        let owner = Pubkey::new_from_array([5; 32]);
+        let mut data = vec![0u8; 16];
+        let minimum_balance = Rent::default().minimum_balance(data.len());
+        let mut lamports = minimum_balance;
+        let account_info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(key, &account_info);
+        let payload = ParsedPayload {
+            target: Some(key),
+            ..Default::default()
+        };
+        let rule = Rule::IsRentExempt {
+            field: PayloadKey::Target,
+        };
+
+        let (result, _) = rule.ll_validate(&accounts, &payload);
+        assert!(result);
+    }
+
+    #[test]
+    fn is_rent_exempt_fails_one_lamport_below_minimum_balance() {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+
+        let key = Pubkey::new_from_array([6; 32]);
+        let owner = Pubkey::new_from_array([7; 32]);
+        let mut data = vec![0u8; 16];
+        let minimum_balance = Rent::default().minimum_balance(data.len());
+        let mut lamports = minimum_balance - 1;
+        let account_info =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        let mut accounts = HashMap::new();
+        accounts.insert(key, &account_info);
+        let payload = ParsedPayload {
+            target: Some(key),
+            ..Default::default()
+        };
+        let rule = Rule::IsRentExempt {
+            field: PayloadKey::Target,
+        };
+
+        let (result, _) = rule.ll_validate(&accounts, &payload);
+        assert!(!result);
+    }
 }